@@ -1,17 +1,28 @@
+use std::borrow::Cow;
 use std::fmt::Write;
 use std::io::Read;
 
-use pyo3::{exceptions::PyValueError, prelude::*, types::PyUnicode};
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList, PyUnicode},
+};
 
 use hashbrown::HashMap;
 use ouroboros::self_referencing;
+use rayon::prelude::*;
 use vaporetto_rules::{
     sentence_filters::{ConcatGraphemeClustersFilter, KyteaWsConstFilter},
     string_filters::KyteaFullwidthFilter,
     SentenceFilter, StringFilter,
 };
-use vaporetto_rust::errors::VaporettoError;
-use vaporetto_rust::{CharacterType, KyteaModel, Model, Predictor, Sentence};
+use vaporetto_rust::errors::VaporettoError as PredictError;
+use vaporetto_rust::{CharacterBoundary, CharacterType, KyteaModel, Model, Predictor, Sentence};
+
+// Raised instead of silently returning an empty result when `raise_on_error` is set (the
+// default); see `Vaporetto::predict_checked`.
+create_exception!(vaporetto, VaporettoError, PyException);
 
 /// Representation of a token.
 #[pyclass]
@@ -69,6 +80,17 @@ impl Token {
         self.list.borrow(py).n_tags
     }
 
+    /// Return the confidence score at this token's end boundary.
+    ///
+    /// ``None`` if the tokenizer was not created with ``predict_scores = True``, or if this is
+    /// the last token in the sentence (it has no following boundary).
+    ///
+    /// :rtype: Optional[float]
+    fn boundary_score(&self, py: Python) -> Option<f64> {
+        let list = self.list.borrow(py);
+        list.scores.get(list.surfaces[self.index].2 - 1).copied()
+    }
+
     fn __str__(&self, py: Python) -> Py<PyUnicode> {
         self.surface(py)
     }
@@ -124,6 +146,9 @@ struct TokenList {
     surfaces: Vec<(Py<PyUnicode>, usize, usize)>,
     tags: Vec<Option<Py<PyUnicode>>>,
     n_tags: usize,
+    // Per-boundary confidence scores, populated only when `Vaporetto.predict_scores` is set;
+    // empty otherwise. `scores[i]` is the score for the boundary between characters `i`/`i+1`.
+    scores: Vec<f64>,
 }
 
 #[pymethods]
@@ -148,6 +173,24 @@ impl TokenList {
             len,
         }
     }
+
+    /// Return the full per-boundary confidence scores for the tokenized sentence.
+    ///
+    /// Empty unless the tokenizer was created with ``predict_scores = True``.
+    ///
+    /// :rtype: list[float]
+    fn scores(&self) -> Vec<f64> {
+        self.scores.clone()
+    }
+}
+
+/// Plain, `Send` snapshot of a predicted sentence, built off-GIL and turned into Python objects
+/// (`TokenList`/`PyUnicode`) once the GIL is reacquired.
+struct RawTokens {
+    tokens: Vec<(String, usize, usize)>,
+    tags: Vec<Option<String>>,
+    n_tags: usize,
+    scores: Vec<f64>,
 }
 
 #[self_referencing]
@@ -161,49 +204,293 @@ struct PredictorWrapper {
     norm_sentence_buf: Sentence<'static, 'this>,
 }
 
+/// Checks that `scores` has exactly one entry per character boundary in a sentence of `n_chars`
+/// characters (an `n_chars`-character sentence has `n_chars - 1` internal boundaries). Only
+/// asserts in debug builds: `Sentence::boundary_scores()` silently returning an empty or
+/// mis-sized vector should fail loudly here rather than quietly misaligning every downstream
+/// `Token::boundary_score()` lookup.
+fn debug_assert_scores_aligned(scores: &[f64], n_chars: usize) {
+    debug_assert_eq!(
+        scores.len(),
+        n_chars.saturating_sub(1),
+        "Sentence::boundary_scores() must return one score per character boundary"
+    );
+}
+
+/// Runs the predictor (plus the post filters) against `sentence_buf`/`norm_sentence_buf`.
+///
+/// This is shared between the single-text path, which drives a pair of buffers owned by
+/// `PredictorWrapper`, and the batch path, which drives one pair of buffers per worker thread.
+///
+/// If `scores` is `Some`, it is filled with the raw per-boundary confidence scores `Predictor`
+/// computes before thresholding them into the boundary decisions, captured right after
+/// `Predictor::predict` runs and before the post filters (or, in the `normalize` case, the
+/// normalized-to-raw boundary copy) can move boundaries the score vector doesn't account for.
+#[allow(clippy::too_many_arguments)]
+fn run_predict(
+    predictor: &Predictor,
+    sentence_buf: &mut Sentence,
+    norm_sentence_buf: &mut Sentence,
+    text: String,
+    predict_tags: bool,
+    normalize: bool,
+    post_filters: &[Box<dyn SentenceFilter + Sync>],
+    user_dictionary: Option<&UserDictionaryFilter>,
+    mut scores: Option<&mut Vec<f64>>,
+) -> Result<(), PredictError> {
+    sentence_buf.update_raw(text)?;
+    if normalize {
+        let normalizer = KyteaFullwidthFilter;
+        let norm_text = normalizer.filter(sentence_buf.as_raw_text());
+        norm_sentence_buf.update_raw(norm_text)?;
+        predictor.predict(norm_sentence_buf);
+        if let Some(scores) = scores.as_deref_mut() {
+            scores.clear();
+            scores.extend(norm_sentence_buf.boundary_scores().iter().copied().map(f64::from));
+            debug_assert_scores_aligned(scores, norm_sentence_buf.as_raw_text().chars().count());
+        }
+        post_filters
+            .iter()
+            .for_each(|filter| filter.filter(norm_sentence_buf));
+        if let Some(user_dictionary) = user_dictionary {
+            user_dictionary.filter(norm_sentence_buf);
+        }
+        sentence_buf
+            .boundaries_mut()
+            .copy_from_slice(norm_sentence_buf.boundaries());
+        if predict_tags {
+            norm_sentence_buf.fill_tags();
+            if let Some(user_dictionary) = user_dictionary {
+                user_dictionary.stamp_tags(norm_sentence_buf);
+            }
+            sentence_buf.reset_tags(norm_sentence_buf.n_tags());
+            sentence_buf
+                .tags_mut()
+                .clone_from_slice(norm_sentence_buf.tags());
+        }
+    } else {
+        predictor.predict(sentence_buf);
+        if let Some(scores) = scores.as_deref_mut() {
+            scores.clear();
+            scores.extend(sentence_buf.boundary_scores().iter().copied().map(f64::from));
+            debug_assert_scores_aligned(scores, sentence_buf.as_raw_text().chars().count());
+        }
+        post_filters
+            .iter()
+            .for_each(|filter| filter.filter(sentence_buf));
+        if let Some(user_dictionary) = user_dictionary {
+            user_dictionary.filter(sentence_buf);
+        }
+        if predict_tags {
+            sentence_buf.fill_tags();
+            if let Some(user_dictionary) = user_dictionary {
+                user_dictionary.stamp_tags(sentence_buf);
+            }
+        }
+    }
+    Ok(())
+}
+
 impl PredictorWrapper {
+    #[allow(clippy::too_many_arguments)]
     fn predict(
         &mut self,
         text: String,
         predict_tags: bool,
         normalize: bool,
-        post_filters: &[Box<dyn SentenceFilter>],
-    ) -> Result<(), VaporettoError> {
+        post_filters: &[Box<dyn SentenceFilter + Sync>],
+        user_dictionary: Option<&UserDictionaryFilter>,
+        scores: Option<&mut Vec<f64>>,
+    ) -> Result<(), PredictError> {
         self.with_mut(|self_| {
-            self_.sentence_buf.update_raw(text)?;
-            if normalize {
-                let normalizer = KyteaFullwidthFilter;
-                let norm_text = normalizer.filter(self_.sentence_buf.as_raw_text());
-                self_.norm_sentence_buf.update_raw(norm_text)?;
-                self_.predictor.predict(self_.norm_sentence_buf);
-                post_filters
-                    .iter()
-                    .for_each(|filter| filter.filter(self_.norm_sentence_buf));
-                self_
-                    .sentence_buf
-                    .boundaries_mut()
-                    .copy_from_slice(self_.norm_sentence_buf.boundaries());
-                if predict_tags {
-                    self_.norm_sentence_buf.fill_tags();
-                    self_
-                        .sentence_buf
-                        .reset_tags(self_.norm_sentence_buf.n_tags());
-                    self_
-                        .sentence_buf
-                        .tags_mut()
-                        .clone_from_slice(self_.norm_sentence_buf.tags());
-                }
+            run_predict(
+                self_.predictor,
+                self_.sentence_buf,
+                self_.norm_sentence_buf,
+                text,
+                predict_tags,
+                normalize,
+                post_filters,
+                user_dictionary,
+                scores,
+            )
+        })
+    }
+
+    /// Runs `predict` for a whole batch of texts, fanning the work out across whatever rayon
+    /// thread pool is installed around this call (see [`ThreadPoolCache`]). Each worker gets its
+    /// own pair of reusable `Sentence` buffers plus, when `want_scores` is set, its own reusable
+    /// per-boundary scores buffer (the predictor itself is immutable and shared by reference).
+    /// `extract` turns the populated `sentence_buf` (and scores, empty when `want_scores` is
+    /// false) into a plain, `Send` result before the buffers are reused for the next text.
+    #[allow(clippy::too_many_arguments)]
+    fn predict_batch<T: Send>(
+        &self,
+        texts: Vec<String>,
+        predict_tags: bool,
+        normalize: bool,
+        post_filters: &[Box<dyn SentenceFilter + Sync>],
+        user_dictionary: Option<&UserDictionaryFilter>,
+        want_scores: bool,
+        extract: impl Fn(&Sentence, &[f64]) -> T + Sync,
+    ) -> Vec<Result<T, PredictError>> {
+        let predictor = self.borrow_predictor();
+        texts
+            .into_par_iter()
+            .map_init(
+                || (Sentence::default(), Sentence::default(), Vec::<f64>::new()),
+                |(sentence_buf, norm_sentence_buf, scores_buf), text| {
+                    run_predict(
+                        predictor,
+                        sentence_buf,
+                        norm_sentence_buf,
+                        text,
+                        predict_tags,
+                        normalize,
+                        post_filters,
+                        user_dictionary,
+                        want_scores.then_some(scores_buf),
+                    )?;
+                    Ok(extract(sentence_buf, scores_buf.as_slice()))
+                },
+            )
+            .collect()
+    }
+}
+
+/// Cache of rayon thread pools keyed by `n_threads`, so repeated `tokenize_batch` /
+/// `tokenize_to_string_batch` calls with the same thread count reuse a pool instead of paying
+/// `ThreadPoolBuilder::build`'s thread-spawning cost on every call.
+#[derive(Default)]
+struct ThreadPoolCache {
+    pools: HashMap<usize, rayon::ThreadPool>,
+}
+
+impl ThreadPoolCache {
+    fn get_or_build(&mut self, n_threads: usize) -> &rayon::ThreadPool {
+        self.pools.entry(n_threads).or_insert_with(|| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(n_threads)
+                .build()
+                .expect("failed to build rayon thread pool")
+        })
+    }
+}
+
+/// A single trie node for [`UserDictionaryFilter`], keyed by character like the
+/// double-array tries used by fast segmenters such as jieba/cedarwood.
+#[derive(Default, Clone)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    // Index into `UserDictionaryFilter::tags` for a word ending at this node, if any.
+    output: Option<usize>,
+}
+
+/// Runtime user dictionary: a trie over user-supplied surfaces that forces word boundaries
+/// (and the associated tags) wherever a leftmost-longest match is found, the way KyTea's
+/// built-in filters force boundaries around specific character types.
+///
+/// This runs as a post filter like [`KyteaWsConstFilter`]/[`ConcatGraphemeClustersFilter`], so
+/// it composes with them, but it also needs to stamp tags onto the forced token. Tag slots are
+/// only sized correctly once `Sentence::fill_tags` has run, so [`run_predict`] calls
+/// [`UserDictionaryFilter::stamp_tags`] once after tag prediction instead of bundling it into
+/// [`SentenceFilter::filter`].
+#[derive(Default, Clone)]
+struct UserDictionaryFilter {
+    root: TrieNode,
+    tags: Vec<Vec<Option<Cow<'static, str>>>>,
+}
+
+impl UserDictionaryFilter {
+    /// Builds the trie from user-supplied surfaces. `normalize` must match the `Vaporetto`
+    /// instance's own `norm` setting: in the `normalize` path, `filter`/`stamp_tags` run against
+    /// the fullwidth-normalized sentence buffer, so entries must be normalized the same way here
+    /// or a surface written in halfwidth form would never match the fullwidth sentence text.
+    fn new(entries: Vec<(String, Vec<Option<String>>)>, normalize: bool) -> Self {
+        let mut root = TrieNode::default();
+        let mut tags = vec![];
+        for (surface, entry_tags) in entries {
+            let surface = if normalize {
+                KyteaFullwidthFilter.filter(&surface)
             } else {
-                self_.predictor.predict(self_.sentence_buf);
-                post_filters
-                    .iter()
-                    .for_each(|filter| filter.filter(self_.sentence_buf));
-                if predict_tags {
-                    self_.sentence_buf.fill_tags();
-                }
+                surface
+            };
+            let mut node = &mut root;
+            for c in surface.chars() {
+                node = node.children.entry(c).or_default();
             }
-            Ok(())
-        })
+            node.output = Some(tags.len());
+            tags.push(entry_tags.into_iter().map(|t| t.map(Cow::Owned)).collect());
+        }
+        Self { root, tags }
+    }
+
+    /// Finds the leftmost-longest dictionary match starting at `chars[start..]`, returning the
+    /// exclusive end position and the index into `self.tags`.
+    fn longest_match(&self, chars: &[char], start: usize) -> Option<(usize, usize)> {
+        let mut node = &self.root;
+        let mut best = None;
+        for (i, c) in chars[start..].iter().enumerate() {
+            let Some(next) = node.children.get(c) else {
+                break;
+            };
+            node = next;
+            if let Some(output) = node.output {
+                best = Some((start + i + 1, output));
+            }
+        }
+        best
+    }
+}
+
+impl SentenceFilter for UserDictionaryFilter {
+    /// Forces boundaries around every leftmost-longest dictionary match in the sentence.
+    fn filter(&self, sentence: &mut Sentence) {
+        let chars: Vec<char> = sentence.as_raw_text().chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let Some((end, _)) = self.longest_match(&chars, start) else {
+                start += 1;
+                continue;
+            };
+            let boundaries = sentence.boundaries_mut();
+            for b in &mut boundaries[start..end.saturating_sub(1)] {
+                *b = CharacterBoundary::NotWordBoundary;
+            }
+            if start > 0 {
+                boundaries[start - 1] = CharacterBoundary::WordBoundary;
+            }
+            if end < chars.len() {
+                boundaries[end - 1] = CharacterBoundary::WordBoundary;
+            }
+            start = end;
+        }
+    }
+}
+
+impl UserDictionaryFilter {
+    /// Overwrites the tags of every dictionary match with the tags supplied for that entry,
+    /// leaving other tokens' tags untouched. Must run after `Sentence::fill_tags` so the forced
+    /// tags aren't immediately clobbered by the model's own prediction.
+    fn stamp_tags(&self, sentence: &mut Sentence) {
+        let n_tags = sentence.n_tags();
+        if n_tags == 0 {
+            return;
+        }
+        let chars: Vec<char> = sentence.as_raw_text().chars().collect();
+        let mut start = 0;
+        while start < chars.len() {
+            let Some((end, output)) = self.longest_match(&chars, start) else {
+                start += 1;
+                continue;
+            };
+            let tags = sentence.tags_mut();
+            let entry_tags = &self.tags[output];
+            for (i, tag) in entry_tags.iter().enumerate().take(n_tags) {
+                tags[(end - 1) * n_tags + i] = tag.clone();
+            }
+            start = end;
+        }
     }
 }
 
@@ -235,23 +522,39 @@ impl PredictorWrapper {
 ///                 ``O``: Other, ``G``: Grapheme cluster. You can specify multiple types such as
 ///                 ``DGR``.
 /// :param norm: If True, input texts will be normalized beforehand.
+/// :param user_dictionary: A list of ``(surface, tags)`` entries to force as single tokens,
+///                         taking priority over the model's own segmentation. ``tags`` must
+///                         have no more entries than ``predict_tags`` produces.
+/// :param raise_on_error: If True (the default), a failure while tokenizing a text raises
+///                        :class:`.VaporettoError` instead of returning an empty result.
+/// :param predict_scores: If True, :func:`tokenize` and :func:`tokenize_batch` also capture the
+///                        per-boundary confidence scores, readable via :func:`TokenList.scores`
+///                        and :func:`Token.boundary_score`.
 /// :type model: bytes
 /// :type predict_tags: bool
 /// :type wsconst: str
 /// :type norm: bool
+/// :type user_dictionary: list[tuple[str, list[Optional[str]]]]
+/// :type raise_on_error: bool
+/// :type predict_scores: bool
 /// :rtype: vaporetto.Vaporetto
 /// :raises ValueError: if the model is invalid.
 /// :raises ValueError: if the wsconst value is invalid.
 #[pyclass]
-#[pyo3(text_signature = "(model, /, predict_tags = False, wsconst = \"\", norm = True)")]
+#[pyo3(text_signature = "(model, /, predict_tags = False, wsconst = \"\", norm = True, user_dictionary = None, raise_on_error = True, predict_scores = False)")]
 struct Vaporetto {
     wrapper: PredictorWrapper,
     predict_tags: bool,
     normalize: bool,
-    post_filters: Vec<Box<dyn SentenceFilter>>,
+    post_filters: Vec<Box<dyn SentenceFilter + Sync>>,
+    user_dictionary: Option<UserDictionaryFilter>,
+    raise_on_error: bool,
+    predict_scores: bool,
+    scores_buf: Vec<f64>,
     word_cache: HashMap<String, Py<PyUnicode>>,
     tag_cache: HashMap<String, Py<PyUnicode>>,
     string_buf: String,
+    thread_pools: ThreadPoolCache,
 }
 
 impl Vaporetto {
@@ -261,6 +564,9 @@ impl Vaporetto {
         predict_tags: bool,
         wsconst: &str,
         normalize: bool,
+        user_dictionary: Option<Vec<(String, Vec<Option<String>>)>>,
+        raise_on_error: bool,
+        predict_scores: bool,
     ) -> PyResult<Self> {
         // For efficiency, this library creates PyStrings of dictionary words beforehand and uses
         // them if available instead of creating PyStrings every time.
@@ -274,7 +580,7 @@ impl Vaporetto {
             Predictor::new(model, predict_tags).map_err(|e| PyValueError::new_err(e.to_string()))
         })?;
 
-        let mut post_filters: Vec<Box<dyn SentenceFilter>> = vec![];
+        let mut post_filters: Vec<Box<dyn SentenceFilter + Sync>> = vec![];
         for c in wsconst.chars() {
             post_filters.push(match c {
                 'D' => Box::new(KyteaWsConstFilter::new(CharacterType::Digit)),
@@ -300,23 +606,118 @@ impl Vaporetto {
             predict_tags,
             normalize,
             post_filters,
+            user_dictionary: user_dictionary
+                .map(|entries| UserDictionaryFilter::new(entries, normalize)),
+            raise_on_error,
+            predict_scores,
+            scores_buf: Vec::new(),
             word_cache,
             tag_cache: HashMap::new(),
             string_buf: String::new(),
+            thread_pools: ThreadPoolCache::default(),
         })
     }
+
+    /// Runs the predictor against `text`, returning whether a `TokenList`/string snapshot of
+    /// `self.wrapper` is ready to read. When prediction fails, this either raises
+    /// `VaporettoError` (the default) or reports failure by returning `false`, matching the
+    /// previous silent-empty-result behaviour for callers that opted out via `raise_on_error`.
+    ///
+    /// When `self.predict_scores` is set, `self.scores_buf` is refreshed with the per-boundary
+    /// confidence scores for `text`; it is left untouched on failure.
+    fn predict_checked(&mut self, text: String) -> PyResult<bool> {
+        let n_chars = text.chars().count();
+        let result = self.wrapper.predict(
+            text,
+            self.predict_tags,
+            self.normalize,
+            &self.post_filters,
+            self.user_dictionary.as_ref(),
+            self.predict_scores.then_some(&mut self.scores_buf),
+        );
+        checked_result(result, self.raise_on_error, n_chars)
+    }
+}
+
+/// Turns a prediction outcome into what `predict_checked` reports to Python: `Ok(true)` on
+/// success; on failure, either `Err(VaporettoError)` (when `raise_on_error` is set) or `Ok(false)`
+/// to match the previous silent-empty-result behaviour for callers that opted out.
+fn checked_result<E: std::fmt::Display>(
+    result: Result<(), E>,
+    raise_on_error: bool,
+    n_chars: usize,
+) -> PyResult<bool> {
+    match result {
+        Ok(()) => Ok(true),
+        Err(e) if raise_on_error => Err(VaporettoError::new_err(format!(
+            "failed to tokenize text of {n_chars} characters: {e}"
+        ))),
+        Err(_) => Ok(false),
+    }
+}
+
+/// The batch-method counterpart to `checked_result`: converts one worker's outcome into what
+/// `tokenize_batch`/`tokenize_to_string_batch` return for that text. `Ok(value)` on success; on
+/// failure, either `Err(VaporettoError)` (when `raise_on_error` is set) or `default()`, matching
+/// the single-text `raise_on_error=False` behaviour of returning an empty result instead of
+/// silently dropping the failure with no signal at all.
+fn checked_batch_result<T, E: std::fmt::Display>(
+    result: Result<T, E>,
+    raise_on_error: bool,
+    n_chars: usize,
+    default: impl FnOnce() -> T,
+) -> PyResult<T> {
+    match result {
+        Ok(value) => Ok(value),
+        Err(e) if raise_on_error => Err(VaporettoError::new_err(format!(
+            "failed to tokenize text of {n_chars} characters: {e}"
+        ))),
+        Err(_) => Ok(default()),
+    }
+}
+
+/// Writes one CoNLL-U line for a token: ID, FORM and LEMMA (the surface), UPOS (`tag0`, or `_`),
+/// `_` placeholders for the remaining columns, and a MISC field carrying `start_char`/`end_char`
+/// plus `Reading=...` for `tag1`, if present. See `Vaporetto::tokenize_to_conll`.
+fn write_conll_line(
+    buf: &mut String,
+    id: usize,
+    surface: &str,
+    tag0: Option<&str>,
+    tag1: Option<&str>,
+    start: usize,
+    end: usize,
+) {
+    write!(
+        buf,
+        "{}\t{}\t{}\t{}\t_\t_\t_\t_\t_\tstart_char={}|end_char={}",
+        id,
+        surface,
+        surface,
+        tag0.unwrap_or("_"),
+        start,
+        end,
+    )
+    .unwrap();
+    if let Some(reading) = tag1 {
+        write!(buf, "|Reading={}", reading).unwrap();
+    }
+    buf.push('\n');
 }
 
 #[pymethods]
 impl Vaporetto {
     #[new]
-    #[pyo3(signature = (model, /, predict_tags=false, wsconst="", norm=true))]
+    #[pyo3(signature = (model, /, predict_tags=false, wsconst="", norm=true, user_dictionary=None, raise_on_error=true, predict_scores=false))]
     fn new(
         py: Python,
         model: &[u8],
         predict_tags: bool,
         wsconst: &str,
         norm: bool,
+        user_dictionary: Option<Vec<(String, Vec<Option<String>>)>>,
+        raise_on_error: bool,
+        predict_scores: bool,
     ) -> PyResult<Self> {
         let mut buf = vec![];
         let (model, _) = py.allow_threads(|| {
@@ -327,7 +728,16 @@ impl Vaporetto {
                 .map_err(|e| PyValueError::new_err(e.to_string()))?;
             Model::read_slice(&buf).map_err(|e| PyValueError::new_err(e.to_string()))
         })?;
-        Self::create_internal(py, model, predict_tags, wsconst, norm)
+        Self::create_internal(
+            py,
+            model,
+            predict_tags,
+            wsconst,
+            norm,
+            user_dictionary,
+            raise_on_error,
+            predict_scores,
+        )
     }
 
     /// Create a new Vaporetto instance from a KyTea's model.
@@ -337,27 +747,61 @@ impl Vaporetto {
     /// :param model: A byte sequence of the model.
     /// :param wsconst: Does not split the specified character types.
     /// :param norm: If True, input texts will be normalized beforehand.
+    /// :param user_dictionary: A list of ``(surface, tags)`` entries to force as single tokens.
+    /// :param raise_on_error: If True (the default), a failure while tokenizing a text raises
+    ///                        :class:`.VaporettoError` instead of returning an empty result.
+    /// :param predict_scores: If True, :func:`tokenize` and :func:`tokenize_batch` also capture
+    ///                        the per-boundary confidence scores.
     /// :type model: bytes
     /// :type wsconst: str
     /// :type norm: bool
+    /// :type user_dictionary: list[tuple[str, list[Optional[str]]]]
+    /// :type raise_on_error: bool
+    /// :type predict_scores: bool
     /// :rtype: vaporetto.Vaporetto
     /// :raises ValueError: if the model is invalid.
     /// :raises ValueError: if the wsconst value is invalid.
     #[staticmethod]
-    #[pyo3(signature = (model, /, wsconst="", norm=true))]
-    #[pyo3(text_signature = "(model, /, wsconst = \"\", norm = True)")]
+    #[pyo3(signature = (model, /, wsconst="", norm=true, user_dictionary=None, raise_on_error=true, predict_scores=false))]
+    #[pyo3(text_signature = "(model, /, wsconst = \"\", norm = True, user_dictionary = None, raise_on_error = True, predict_scores = False)")]
     fn create_from_kytea_model(
         py: Python,
         model: &[u8],
         wsconst: &str,
         norm: bool,
+        user_dictionary: Option<Vec<(String, Vec<Option<String>>)>>,
+        raise_on_error: bool,
+        predict_scores: bool,
     ) -> PyResult<Self> {
         let model = py.allow_threads(|| {
             let kytea_model =
                 KyteaModel::read(model).map_err(|e| PyValueError::new_err(e.to_string()))?;
             Model::try_from(kytea_model).map_err(|e| PyValueError::new_err(e.to_string()))
         })?;
-        Self::create_internal(py, model, false, wsconst, norm)
+        Self::create_internal(
+            py,
+            model,
+            false,
+            wsconst,
+            norm,
+            user_dictionary,
+            raise_on_error,
+            predict_scores,
+        )
+    }
+
+    /// Install (or replace) the runtime user dictionary.
+    ///
+    /// Every entry forces its surface to be tokenized as a single word wherever it occurs,
+    /// with the given tags, taking priority over both the model's segmentation and the
+    /// built-in ``wsconst`` filters. Matching is leftmost-longest, like the model's own
+    /// dictionary lookup.
+    ///
+    /// :param words: A list of ``(surface, tags)`` entries.
+    /// :type words: list[tuple[str, list[Optional[str]]]]
+    #[pyo3(signature = (words, /))]
+    fn add_user_dictionary(&mut self, words: Vec<(String, Vec<Option<String>>)>) {
+        self.user_dictionary = Some(UserDictionaryFilter::new(words, self.normalize));
     }
 
     /// Tokenize a given text and return as a list of tokens.
@@ -365,18 +809,16 @@ impl Vaporetto {
     /// :param text: A text to tokenize.
     /// :type text: str
     /// :rtype: vaporetto.TokenList
+    /// :raises VaporettoError: if tokenization fails and ``raise_on_error`` is True.
     #[pyo3(signature = (text, /))]
-    fn tokenize(&mut self, py: Python, text: String) -> TokenList {
-        if self
-            .wrapper
-            .predict(text, self.predict_tags, self.normalize, &self.post_filters)
-            .is_err()
-        {
-            return TokenList {
+    fn tokenize(&mut self, py: Python, text: String) -> PyResult<TokenList> {
+        if !self.predict_checked(text)? {
+            return Ok(TokenList {
                 surfaces: vec![],
                 tags: vec![],
                 n_tags: 0,
-            };
+                scores: vec![],
+            });
         }
         let s = self.wrapper.borrow_sentence_buf();
         let surfaces = s
@@ -406,11 +848,12 @@ impl Vaporetto {
                 })
             })
             .collect();
-        TokenList {
+        Ok(TokenList {
             surfaces,
             tags,
             n_tags: s.n_tags(),
-        }
+            scores: self.scores_buf.clone(),
+        })
     }
 
     /// Tokenize a given text and return as a string.
@@ -418,29 +861,395 @@ impl Vaporetto {
     /// :param text: A text to tokenize.
     /// :type text: str
     /// :rtype: str
+    /// :raises VaporettoError: if tokenization fails and ``raise_on_error`` is True.
     #[pyo3(signature = (text, /))]
-    fn tokenize_to_string(&mut self, py: Python, text: String) -> Py<PyUnicode> {
-        if self
-            .wrapper
-            .predict(text, self.predict_tags, self.normalize, &self.post_filters)
-            .is_ok()
-        {
+    fn tokenize_to_string(&mut self, py: Python, text: String) -> PyResult<Py<PyUnicode>> {
+        if self.predict_checked(text)? {
             self.wrapper
                 .borrow_sentence_buf()
                 .write_tokenized_text(&mut self.string_buf);
         } else {
             self.string_buf.clear();
         }
-        PyUnicode::new(py, &self.string_buf).into()
+        Ok(PyUnicode::new(py, &self.string_buf).into())
+    }
+
+    /// Tokenize a given text and return as a CoNLL-U formatted string.
+    ///
+    /// Each line holds one token's ID, FORM and LEMMA (the surface, since Vaporetto has no
+    /// lemmatizer), UPOS (the first predicted tag, if any), ``_`` placeholders for the
+    /// remaining CoNLL-U columns, and a MISC field carrying ``start_char``/``end_char`` plus
+    /// ``Reading=...`` for the second predicted tag, if any.
+    ///
+    /// :param text: A text to tokenize.
+    /// :type text: str
+    /// :rtype: str
+    /// :raises VaporettoError: if tokenization fails and ``raise_on_error`` is True.
+    #[pyo3(signature = (text, /))]
+    fn tokenize_to_conll(&mut self, py: Python, text: String) -> PyResult<Py<PyUnicode>> {
+        self.string_buf.clear();
+        if self.predict_checked(text)? {
+            let s = self.wrapper.borrow_sentence_buf();
+            let n_tags = s.n_tags();
+            let tags = s.tags();
+            for (i, token) in s.iter_tokens().enumerate() {
+                let pos = token.end() - 1;
+                let tag = |j: usize| {
+                    tags.get(pos * n_tags + j)
+                        .and_then(|t| t.as_ref())
+                        .map(|t| t.as_ref())
+                };
+                write_conll_line(
+                    &mut self.string_buf,
+                    i + 1,
+                    token.surface(),
+                    tag(0),
+                    tag(1),
+                    token.start(),
+                    token.end(),
+                );
+            }
+        }
+        Ok(PyUnicode::new(py, &self.string_buf).into())
+    }
+
+    /// Tokenize a given text and return as a list of dicts.
+    ///
+    /// Each dict holds the keys ``surface``, ``start``, ``end`` and ``tags`` (the list of
+    /// predicted tags for that token, in the same order as :func:`Token.tag`).
+    ///
+    /// :param text: A text to tokenize.
+    /// :type text: str
+    /// :rtype: list[dict]
+    /// :raises VaporettoError: if tokenization fails and ``raise_on_error`` is True.
+    #[pyo3(signature = (text, /))]
+    fn tokenize_to_json(&mut self, py: Python, text: String) -> PyResult<Py<PyList>> {
+        let list = PyList::empty(py);
+        if self.predict_checked(text)? {
+            let s = self.wrapper.borrow_sentence_buf();
+            let n_tags = s.n_tags();
+            let tags = s.tags();
+            for token in s.iter_tokens() {
+                let surface = self
+                    .word_cache
+                    .get(token.surface())
+                    .map(|surf| surf.clone_ref(py))
+                    .unwrap_or_else(|| PyUnicode::new(py, token.surface()).into());
+                let pos = token.end() - 1;
+                let token_tags = PyList::empty(py);
+                for i in 0..n_tags {
+                    let tag = tags
+                        .get(pos * n_tags + i)
+                        .and_then(|t| t.as_ref())
+                        .map(|tag| {
+                            self.tag_cache
+                                .raw_entry_mut()
+                                .from_key(tag.as_ref())
+                                .or_insert_with(|| (tag.to_string(), PyUnicode::new(py, tag.as_ref()).into()))
+                                .1
+                                .clone_ref(py)
+                        });
+                    token_tags.append(tag).unwrap();
+                }
+                let dict = PyDict::new(py);
+                dict.set_item("surface", surface).unwrap();
+                dict.set_item("start", token.start()).unwrap();
+                dict.set_item("end", token.end()).unwrap();
+                dict.set_item("tags", token_tags).unwrap();
+                list.append(dict).unwrap();
+            }
+        }
+        Ok(list.into())
+    }
+
+    /// Tokenize a batch of texts, fanning the work out across a thread pool.
+    ///
+    /// This is equivalent to calling :func:`tokenize` on each text, but the predictor runs off
+    /// the GIL and in parallel, which is substantially faster for corpus-scale inputs. Failures
+    /// are handled the same way as :func:`tokenize`: if ``raise_on_error`` is True (the default),
+    /// the first failure in the batch raises :class:`.VaporettoError`; otherwise, each failed
+    /// text contributes an empty :class:`.TokenList` to the result.
+    ///
+    /// :param texts: A list of texts to tokenize.
+    /// :param n_threads: The number of worker threads to use.
+    /// :type texts: list[str]
+    /// :type n_threads: int
+    /// :rtype: list[vaporetto.TokenList]
+    /// :raises VaporettoError: if tokenizing any text fails and ``raise_on_error`` is True.
+    #[pyo3(signature = (texts, /, n_threads=1))]
+    fn tokenize_batch(
+        &mut self,
+        py: Python,
+        texts: Vec<String>,
+        n_threads: usize,
+    ) -> PyResult<Vec<TokenList>> {
+        let pool = self.thread_pools.get_or_build(n_threads);
+        let predict_tags = self.predict_tags;
+        let normalize = self.normalize;
+        let post_filters = &self.post_filters;
+        let user_dictionary = self.user_dictionary.as_ref();
+        let want_scores = self.predict_scores;
+        let raise_on_error = self.raise_on_error;
+        let char_counts: Vec<usize> = texts.iter().map(|text| text.chars().count()).collect();
+        let wrapper = &self.wrapper;
+        let results = py.allow_threads(|| {
+            pool.install(|| {
+                wrapper.predict_batch(
+                    texts,
+                    predict_tags,
+                    normalize,
+                    post_filters,
+                    user_dictionary,
+                    want_scores,
+                    |s, scores| RawTokens {
+                        tokens: s
+                            .iter_tokens()
+                            .map(|token| (token.surface().to_string(), token.start(), token.end()))
+                            .collect(),
+                        tags: s
+                            .tags()
+                            .iter()
+                            .map(|tag| tag.as_ref().map(|tag| tag.to_string()))
+                            .collect(),
+                        n_tags: s.n_tags(),
+                        scores: scores.to_vec(),
+                    },
+                )
+            })
+        });
+        results
+            .into_iter()
+            .zip(char_counts)
+            .map(|(result, n_chars)| {
+                let raw = checked_batch_result(result, raise_on_error, n_chars, || RawTokens {
+                    tokens: vec![],
+                    tags: vec![],
+                    n_tags: 0,
+                    scores: vec![],
+                })?;
+                let surfaces = raw
+                    .tokens
+                    .into_iter()
+                    .map(|(surface, start, end)| {
+                        let surface = self
+                            .word_cache
+                            .get(&surface)
+                            .map(|surf| surf.clone_ref(py))
+                            .unwrap_or_else(|| PyUnicode::new(py, &surface).into());
+                        (surface, start, end)
+                    })
+                    .collect();
+                let tags = raw
+                    .tags
+                    .into_iter()
+                    .map(|tag| {
+                        tag.map(|tag| {
+                            self.tag_cache
+                                .raw_entry_mut()
+                                .from_key(tag.as_str())
+                                .or_insert_with(|| (tag.clone(), PyUnicode::new(py, &tag).into()))
+                                .1
+                                .clone_ref(py)
+                        })
+                    })
+                    .collect();
+                Ok(TokenList {
+                    surfaces,
+                    tags,
+                    n_tags: raw.n_tags,
+                    scores: raw.scores,
+                })
+            })
+            .collect()
+    }
+
+    /// Tokenize a batch of texts and return each result as a string.
+    ///
+    /// See :func:`tokenize_batch` for the threading and error-handling behaviour.
+    ///
+    /// :param texts: A list of texts to tokenize.
+    /// :param n_threads: The number of worker threads to use.
+    /// :type texts: list[str]
+    /// :type n_threads: int
+    /// :rtype: list[str]
+    /// :raises VaporettoError: if tokenizing any text fails and ``raise_on_error`` is True.
+    #[pyo3(signature = (texts, /, n_threads=1))]
+    fn tokenize_to_string_batch(
+        &mut self,
+        py: Python,
+        texts: Vec<String>,
+        n_threads: usize,
+    ) -> PyResult<Vec<Py<PyUnicode>>> {
+        let pool = self.thread_pools.get_or_build(n_threads);
+        let predict_tags = self.predict_tags;
+        let normalize = self.normalize;
+        let post_filters = &self.post_filters;
+        let user_dictionary = self.user_dictionary.as_ref();
+        let raise_on_error = self.raise_on_error;
+        let char_counts: Vec<usize> = texts.iter().map(|text| text.chars().count()).collect();
+        let wrapper = &self.wrapper;
+        let results = py.allow_threads(|| {
+            pool.install(|| {
+                wrapper.predict_batch(
+                    texts,
+                    predict_tags,
+                    normalize,
+                    post_filters,
+                    user_dictionary,
+                    false,
+                    |s, _scores| {
+                        let mut buf = String::new();
+                        s.write_tokenized_text(&mut buf);
+                        buf
+                    },
+                )
+            })
+        });
+        results
+            .into_iter()
+            .zip(char_counts)
+            .map(|(result, n_chars)| {
+                let text = checked_batch_result(result, raise_on_error, n_chars, String::new)?;
+                Ok(PyUnicode::new(py, &text).into())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_batch_result_raises_on_error_by_default() {
+        let result: Result<u8, &str> = Err("boom");
+        assert!(checked_batch_result(result, true, 3, || 0).is_err());
+    }
+
+    #[test]
+    fn checked_batch_result_uses_the_default_without_raising_when_opted_out() {
+        let result: Result<u8, &str> = Err("boom");
+        assert_eq!(checked_batch_result(result, false, 3, || 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn checked_batch_result_returns_the_value_on_success() {
+        let result: Result<u8, &str> = Ok(42);
+        assert_eq!(checked_batch_result(result, true, 3, || 0).unwrap(), 42);
+    }
+
+    #[test]
+    fn conll_line_has_nine_columns_plus_char_offsets_in_misc() {
+        let mut buf = String::new();
+        write_conll_line(&mut buf, 1, "社長", Some("名詞"), Some("シャチョー"), 2, 4);
+        assert_eq!(
+            buf,
+            "1\t社長\t社長\t名詞\t_\t_\t_\t_\t_\tstart_char=2|end_char=4|Reading=シャチョー\n"
+        );
+    }
+
+    #[test]
+    fn conll_line_uses_underscore_placeholder_when_tags_are_absent() {
+        let mut buf = String::new();
+        write_conll_line(&mut buf, 1, "猫", None, None, 0, 1);
+        assert_eq!(buf, "1\t猫\t猫\t_\t_\t_\t_\t_\t_\tstart_char=0|end_char=1\n");
+    }
+
+    #[test]
+    fn checked_result_raises_on_error_by_default() {
+        let result: Result<(), &str> = Err("boom");
+        assert!(checked_result(result, true, 3).is_err());
+    }
+
+    #[test]
+    fn checked_result_returns_false_without_raising_when_opted_out() {
+        let result: Result<(), &str> = Err("boom");
+        assert_eq!(checked_result(result, false, 3).unwrap(), false);
+    }
+
+    #[test]
+    fn checked_result_returns_true_on_success() {
+        let result: Result<(), &str> = Ok(());
+        assert_eq!(checked_result(result, true, 3).unwrap(), true);
+    }
+
+    #[test]
+    fn scores_alignment_check_accepts_one_score_per_boundary() {
+        // A 4-character sentence has 3 internal boundaries.
+        debug_assert_scores_aligned(&[0.1, 0.2, 0.3], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scores_alignment_check_rejects_a_mismatched_vector() {
+        debug_assert_scores_aligned(&[0.1, 0.2], 4);
+    }
+
+    #[test]
+    fn thread_pool_cache_reuses_the_pool_for_the_same_thread_count() {
+        let mut cache = ThreadPoolCache::default();
+        let first: *const rayon::ThreadPool = cache.get_or_build(2);
+        let second: *const rayon::ThreadPool = cache.get_or_build(2);
+        assert!(std::ptr::eq(first, second));
+
+        let third: *const rayon::ThreadPool = cache.get_or_build(4);
+        assert!(!std::ptr::eq(first, third));
+    }
+
+    #[test]
+    fn user_dictionary_filter_forces_boundaries_and_stamps_tags() {
+        let mut sentence = Sentence::default();
+        sentence.update_raw("ABCDE".to_string()).unwrap();
+        for b in sentence.boundaries_mut() {
+            *b = CharacterBoundary::NotWordBoundary;
+        }
+        sentence.reset_tags(1);
+
+        let dict = UserDictionaryFilter::new(vec![("BC".to_string(), vec![Some("TAG".to_string())])], false);
+        dict.filter(&mut sentence);
+
+        // "A|BC|DE": boundaries are forced on both sides of the "BC" match, not inside it.
+        assert_eq!(sentence.boundaries()[0], CharacterBoundary::WordBoundary);
+        assert_eq!(sentence.boundaries()[1], CharacterBoundary::NotWordBoundary);
+        assert_eq!(sentence.boundaries()[2], CharacterBoundary::WordBoundary);
+        assert_eq!(sentence.boundaries()[3], CharacterBoundary::NotWordBoundary);
+
+        dict.stamp_tags(&mut sentence);
+        let n_tags = sentence.n_tags();
+        let tags = sentence.tags();
+        assert_eq!(
+            tags[2 /* end of "BC" - 1 */ * n_tags].as_deref(),
+            Some("TAG")
+        );
+    }
+
+    #[test]
+    fn user_dictionary_filter_normalizes_halfwidth_surfaces_when_normalize_is_set() {
+        let mut sentence = Sentence::default();
+        // Fullwidth "ＡＢＣ", as it would appear in `norm_sentence_buf` after `KyteaFullwidthFilter`
+        // normalizes halfwidth input text.
+        sentence.update_raw("\u{FF21}\u{FF22}\u{FF23}".to_string()).unwrap();
+        for b in sentence.boundaries_mut() {
+            *b = CharacterBoundary::WordBoundary;
+        }
+
+        // The dictionary entry is written in halfwidth form, as a user would type it; `new` must
+        // normalize it the same way so it still matches the normalized sentence text.
+        let dict = UserDictionaryFilter::new(vec![("ABC".to_string(), vec![])], true);
+        dict.filter(&mut sentence);
+
+        assert_eq!(sentence.boundaries()[0], CharacterBoundary::NotWordBoundary);
+        assert_eq!(sentence.boundaries()[1], CharacterBoundary::NotWordBoundary);
     }
 }
 
 #[pymodule]
-fn vaporetto(_py: Python, m: &PyModule) -> PyResult<()> {
+fn vaporetto(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Vaporetto>()?;
     m.add_class::<TokenList>()?;
     m.add_class::<TokenIterator>()?;
     m.add_class::<Token>()?;
+    m.add("VaporettoError", py.get_type::<VaporettoError>())?;
     m.add("VAPORETTO_VERSION", vaporetto_rust::VERSION)?;
     Ok(())
 }